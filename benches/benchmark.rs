@@ -14,15 +14,30 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("attempt 3", move |b| {
         b.iter(|| mix_mono_to_stereo_3(&mut dst, &src, 1.0, 1.0))
     });
+    #[cfg(target_arch = "x86_64")]
+    {
+        let src = vec![0.0; BENCHMARK_SAMPLES];
+        let mut dst = vec![0.0; BENCHMARK_SAMPLES * 2];
+        c.bench_function("rust intrinsics", move |b| {
+            b.iter(|| mix_mono_to_stereo_intrinsics_rust(&mut dst, &src, 1.0, 1.0))
+        });
+        let src = vec![0.0; BENCHMARK_SAMPLES];
+        let mut dst = vec![0.0; BENCHMARK_SAMPLES * 2];
+        c.bench_function("C intrinsics", move |b| {
+            b.iter(|| mix_mono_to_stereo_intrinsics_safe(&mut dst, &src, 1.0, 1.0))
+        });
+    }
+    // whichever SIMD backend the target provides (SSE on x86_64, portable
+    // `core::simd` elsewhere)
     let src = vec![0.0; BENCHMARK_SAMPLES];
     let mut dst = vec![0.0; BENCHMARK_SAMPLES * 2];
-    c.bench_function("rust intrinsics", move |b| {
-        b.iter(|| mix_mono_to_stereo_intrinsics_rust(&mut dst, &src, 1.0, 1.0))
+    c.bench_function("simd backend", move |b| {
+        b.iter(|| mix_mono_to_stereo_backend(&mut dst, &src, 1.0, 1.0))
     });
     let src = vec![0.0; BENCHMARK_SAMPLES];
     let mut dst = vec![0.0; BENCHMARK_SAMPLES * 2];
-    c.bench_function("C intrinsics", move |b| {
-        b.iter(|| mix_mono_to_stereo_intrinsics_safe(&mut dst, &src, 1.0, 1.0))
+    c.bench_function("runtime dispatch", move |b| {
+        b.iter(|| mix_mono_to_stereo_dispatch(&mut dst, &src, 1.0, 1.0))
     });
 }
 
@@ -1,7 +1,10 @@
 #![allow(dead_code)]
+#![cfg_attr(not(target_arch = "x86_64"), feature(portable_simd))]
 
+#[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
 
+#[cfg(target_arch = "x86_64")]
 pub fn mix_mono_to_stereo_intrinsics_rust(dst: &mut [f32], src: &[f32], gain_l: f32, gain_r: f32) {
     assert_eq!(src.len() % 4, 0);
     assert_eq!(dst.len(), src.len() * 2);
@@ -44,6 +47,7 @@ pub fn mix_mono_to_stereo_intrinsics_rust(dst: &mut [f32], src: &[f32], gain_l:
     }
 }
 
+#[cfg(target_arch = "x86_64")]
 extern "C" {
     fn mix_mono_to_stereo_intrinsics(
         samples: i32,
@@ -55,6 +59,7 @@ extern "C" {
 }
 
 /// Wrap the version written in C intrinsics in a safe rust wrapper
+#[cfg(target_arch = "x86_64")]
 pub fn mix_mono_to_stereo_intrinsics_safe(dst: &mut [f32], src: &[f32], gain_l: f32, gain_r: f32) {
     unsafe {
         mix_mono_to_stereo_intrinsics(
@@ -112,9 +117,413 @@ pub fn mix_mono_to_stereo_3(
     }
 }
 
+/// 256-bit AVX mono-to-stereo mixer, processing eight mono samples (sixteen
+/// interleaved stereo floats) per iteration.
+///
+/// The per-lane `_mm256_unpacklo_ps`/`_mm256_unpackhi_ps` interleave happens
+/// inside each 128-bit lane, so the two halves have to be stitched back into
+/// sample order with `_mm256_permute2f128_ps` before storing.
+///
+/// Source lengths that are not a multiple of eight are finished with a scalar
+/// tail, so any source length is accepted. The SSE path the dispatcher selects
+/// on CPUs without AVX still requires a source length that is a multiple of four.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx")]
+unsafe fn mix_mono_to_stereo_avx(dst: &mut [f32], src: &[f32], gain_l: f32, gain_r: f32) {
+    assert_eq!(dst.len(), src.len() * 2);
+    let src_ptr = src.as_ptr();
+    let dst_ptr = dst.as_mut_ptr();
+
+    let mul_l = _mm256_set1_ps(gain_l);
+    let mul_r = _mm256_set1_ps(gain_r);
+
+    // process the source samples in blocks of eight
+    let mut i = 0;
+    while i + 8 <= src.len() {
+        // input = | src(i + 0) | ... | src(i + 7) |
+        let input = _mm256_loadu_ps(src_ptr.add(i));
+        let out_l = _mm256_mul_ps(input, mul_l);
+        let out_r = _mm256_mul_ps(input, mul_r);
+
+        // the unpack operates within each 128-bit lane, giving
+        // lo = | l0 r0 l1 r1 | l4 r4 l5 r5 |
+        // hi = | l2 r2 l3 r3 | l6 r6 l7 r7 |
+        let lo = _mm256_unpacklo_ps(out_l, out_r);
+        let hi = _mm256_unpackhi_ps(out_l, out_r);
+
+        // recombine the lanes into sample order before storing
+        // out0 = | l0 r0 l1 r1 l2 r2 l3 r3 |
+        // out1 = | l4 r4 l5 r5 l6 r6 l7 r7 |
+        let out0 = _mm256_permute2f128_ps(lo, hi, 0x20);
+        let out1 = _mm256_permute2f128_ps(lo, hi, 0x31);
+
+        _mm256_storeu_ps(dst_ptr.add(2 * i), out0);
+        _mm256_storeu_ps(dst_ptr.add(2 * i + 8), out1);
+
+        i += 8;
+    }
+
+    // handle the tail samples that don't fill a full block of eight
+    while i < src.len() {
+        let x = *src_ptr.add(i);
+        *dst_ptr.add(2 * i) = x * gain_l;
+        *dst_ptr.add(2 * i + 1) = x * gain_r;
+        i += 1;
+    }
+}
+
+/// Scalar fallback used by the dispatcher on targets other than x86_64.
+fn mix_mono_to_stereo_scalar(dst: &mut [f32], src: &[f32], gain_l: f32, gain_r: f32) {
+    let dst_known_bounds = &mut dst[0..src.len() * 2];
+    for i in 0..src.len() {
+        dst_known_bounds[i * 2] = src[i] * gain_l;
+        dst_known_bounds[i * 2 + 1] = src[i] * gain_r;
+    }
+}
+
+/// There is no atomic function-pointer type, so the chosen implementation is
+/// cached as a plain function pointer cast into an `AtomicPtr<()>`.
+type MixFn = fn(&mut [f32], &[f32], f32, f32);
+
+#[cfg(target_arch = "x86_64")]
+fn mix_mono_to_stereo_avx_safe(dst: &mut [f32], src: &[f32], gain_l: f32, gain_r: f32) {
+    // safety: only installed by the dispatcher after detecting the AVX feature
+    unsafe { mix_mono_to_stereo_avx(dst, src, gain_l, gain_r) }
+}
+
+fn mix_mono_to_stereo_resolve() -> MixFn {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx") {
+            mix_mono_to_stereo_avx_safe
+        } else {
+            mix_mono_to_stereo_intrinsics_rust
+        }
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        mix_mono_to_stereo_scalar
+    }
+}
+
+/// Mix a mono source into an interleaved stereo buffer, dispatching to the
+/// widest SIMD implementation the running CPU supports: AVX, else SSE, on
+/// x86_64. Other targets always use the scalar fallback.
+///
+/// The feature detection runs once; the resolved function pointer is cached in
+/// an `AtomicPtr` so subsequent calls go straight to the chosen backend.
+pub fn mix_mono_to_stereo_dispatch(dst: &mut [f32], src: &[f32], gain_l: f32, gain_r: f32) {
+    use core::sync::atomic::{AtomicPtr, Ordering};
+    static CACHE: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+    let cached = CACHE.load(Ordering::Relaxed);
+    let func: MixFn = if cached.is_null() {
+        let resolved = mix_mono_to_stereo_resolve();
+        CACHE.store(resolved as *mut (), Ordering::Relaxed);
+        resolved
+    } else {
+        // safety: only ever stored as a `MixFn` above
+        unsafe { core::mem::transmute::<*mut (), MixFn>(cached) }
+    };
+    func(dst, src, gain_l, gain_r);
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+use core::simd::{simd_swizzle, Simd};
+
+/// Portable mono-to-stereo mixer written against `core::simd`.
+///
+/// This is the architecture-independent counterpart to
+/// `mix_mono_to_stereo_intrinsics_rust`: it multiplies four mono samples by the
+/// left/right gains and uses `simd_swizzle!` to interleave the results into
+/// `| l0 r0 l1 r1 |` / `| l2 r2 l3 r3 |` pairs before storing. On non-x86
+/// targets (e.g. aarch64/NEON) this is the backend the benchmark exercises.
+#[cfg(not(target_arch = "x86_64"))]
+pub fn mix_mono_to_stereo_portable(dst: &mut [f32], src: &[f32], gain_l: f32, gain_r: f32) {
+    assert_eq!(src.len() % 4, 0);
+    assert_eq!(dst.len(), src.len() * 2);
+    let mul_l = Simd::<f32, 4>::splat(gain_l);
+    let mul_r = Simd::<f32, 4>::splat(gain_r);
+
+    let mut i = 0;
+    while i < src.len() {
+        let input = Simd::<f32, 4>::from_slice(&src[i..i + 4]);
+        let out_l = input * mul_l;
+        let out_r = input * mul_r;
+
+        // interleave across the two vectors: swizzle indices 0..4 select from
+        // out_l and 4..8 select from out_r
+        let lo = simd_swizzle!(out_l, out_r, [0, 4, 1, 5]);
+        let hi = simd_swizzle!(out_l, out_r, [2, 6, 3, 7]);
+
+        dst[2 * i..2 * i + 4].copy_from_slice(&lo.to_array());
+        dst[2 * i + 4..2 * i + 8].copy_from_slice(&hi.to_array());
+
+        i += 4;
+    }
+}
+
+/// The SIMD mono-to-stereo backend available on the current target: the SSE
+/// intrinsics path on x86_64, the portable `core::simd` path elsewhere.
+#[cfg(target_arch = "x86_64")]
+pub fn mix_mono_to_stereo_backend(dst: &mut [f32], src: &[f32], gain_l: f32, gain_r: f32) {
+    mix_mono_to_stereo_intrinsics_rust(dst, src, gain_l, gain_r)
+}
+
+/// The SIMD mono-to-stereo backend available on the current target: the SSE
+/// intrinsics path on x86_64, the portable `core::simd` path elsewhere.
+#[cfg(not(target_arch = "x86_64"))]
+pub fn mix_mono_to_stereo_backend(dst: &mut [f32], src: &[f32], gain_l: f32, gain_r: f32) {
+    mix_mono_to_stereo_portable(dst, src, gain_l, gain_r)
+}
+
+/// Compute the number of output samples produced when resampling `src_len`
+/// input samples from `src_rate` to `dst_rate`.
+fn resample_output_len(src_len: usize, src_rate: u32, dst_rate: u32) -> usize {
+    src_len * dst_rate as usize / src_rate as usize
+}
+
+/// Scalar reference linear resampler.
+///
+/// The source position is tracked as a 16.16 fixed-point accumulator where
+/// `step = (src_rate << 16) / dst_rate`. For output index `j` the position is
+/// `j * step`; its high bits are the integer source index `ipos` and its low
+/// 16 bits are the interpolation weight `frac`. The output is the linear
+/// interpolation `src[ipos] * (1 - frac) + src[ipos + 1] * frac`.
+///
+/// The neighbour index `ipos + 1` is clamped to the last valid source index so
+/// the final output taps never read past the end of `src`.
+///
+/// This is the naive per-index version: each write goes through `dst[j]`, so
+/// the bounds check stays in the loop. See `resample_linear_auto` for the
+/// bounds-proving variant.
+pub fn resample_linear(dst: &mut [MonoSample], src: &[MonoSample], src_rate: u32, dst_rate: u32) {
+    assert_ne!(src.len(), 0);
+    let step = ((src_rate as u64) << 16) / dst_rate as u64;
+    let last = src.len() - 1;
+    let out_len = resample_output_len(src.len(), src_rate, dst_rate);
+    for j in 0..out_len {
+        let pos = step * j as u64;
+        let ipos = (pos >> 16) as usize;
+        let frac = (pos & 0xffff) as f32 / 65536.0;
+        let ip1 = if ipos < last { ipos + 1 } else { last };
+        dst[j] = MonoSample(src[ipos].0 * (1.0 - frac) + src[ip1].0 * frac);
+    }
+}
+
+/// Straight-line variant of the linear resampler.
+///
+/// Slicing the destination to a proven length before the loop (the
+/// `mix_mono_to_stereo_3` trick) removes the per-iteration bounds check on the
+/// destination (the source reads stay checked), but
+/// unlike the mixer this loop still does *not* auto-vectorize: the neighbour
+/// index `ipos = (j * step) >> 16` is data-dependent and not affine in `j`, so
+/// the source reads are a gather. With no AVX2 gather enabled on the default
+/// target LLVM scalarizes those loads and the arithmetic stays scalar too — see
+/// `resample_linear_intrinsics_rust` for the hand-vectorized version that gears
+/// the gather manually.
+pub fn resample_linear_auto(
+    dst: &mut [MonoSample],
+    src: &[MonoSample],
+    src_rate: u32,
+    dst_rate: u32,
+) {
+    assert_ne!(src.len(), 0);
+    let step = ((src_rate as u64) << 16) / dst_rate as u64;
+    let last = src.len() - 1;
+    let dst_known_bounds = &mut dst[0..resample_output_len(src.len(), src_rate, dst_rate)];
+    for (j, out) in dst_known_bounds.iter_mut().enumerate() {
+        let pos = step * j as u64;
+        let ipos = (pos >> 16) as usize;
+        let frac = (pos & 0xffff) as f32 / 65536.0;
+        let ip1 = if ipos < last { ipos + 1 } else { last };
+        *out = MonoSample(src[ipos].0 * (1.0 - frac) + src[ip1].0 * frac);
+    }
+}
+
+/// Explicit SSE linear resampler.
+///
+/// SSE has no scatter/gather, so for each run of four output samples we
+/// precompute the four `(ipos, frac)` pairs on the scalar side, manually gather
+/// the two neighbour samples into `lo`/`hi` vectors, and do the whole lerp with
+/// `_mm_sub_ps`/`_mm_mul_ps`/`_mm_add_ps`: `lo * (1 - frac) + hi * frac`, the
+/// same expression as the scalar versions.
+#[cfg(target_arch = "x86_64")]
+pub fn resample_linear_intrinsics_rust(
+    dst: &mut [MonoSample],
+    src: &[MonoSample],
+    src_rate: u32,
+    dst_rate: u32,
+) {
+    assert_ne!(src.len(), 0);
+    let step = ((src_rate as u64) << 16) / dst_rate as u64;
+    let last = src.len() - 1;
+    let out_len = resample_output_len(src.len(), src_rate, dst_rate);
+    let src_ptr = src.as_ptr() as *const f32;
+    unsafe {
+        let one = _mm_set1_ps(1.0);
+        let mut j = 0;
+        // process the output samples in blocks of four
+        while j + 4 <= out_len {
+            let mut lo = [0.0f32; 4];
+            let mut hi = [0.0f32; 4];
+            let mut frac = [0.0f32; 4];
+            for k in 0..4 {
+                let pos = step * (j + k) as u64;
+                let ipos = (pos >> 16) as usize;
+                let ip1 = if ipos < last { ipos + 1 } else { last };
+                lo[k] = *src_ptr.add(ipos);
+                hi[k] = *src_ptr.add(ip1);
+                frac[k] = (pos & 0xffff) as f32 / 65536.0;
+            }
+            // lo = | src[ipos0] | src[ipos1] | src[ipos2] | src[ipos3] |
+            // hi = | src[ipos0 + 1] | ... |
+            let v_lo = _mm_loadu_ps(lo.as_ptr());
+            let v_hi = _mm_loadu_ps(hi.as_ptr());
+            let v_frac = _mm_loadu_ps(frac.as_ptr());
+            // out = lo * (1 - frac) + hi * frac
+            let out = _mm_add_ps(
+                _mm_mul_ps(v_lo, _mm_sub_ps(one, v_frac)),
+                _mm_mul_ps(v_hi, v_frac),
+            );
+            _mm_storeu_ps((dst.as_mut_ptr() as *mut f32).add(j), out);
+            j += 4;
+        }
+        // handle the tail output samples that don't fill a full block of four
+        while j < out_len {
+            let pos = step * j as u64;
+            let ipos = (pos >> 16) as usize;
+            let f = (pos & 0xffff) as f32 / 65536.0;
+            let ip1 = if ipos < last { ipos + 1 } else { last };
+            dst[j] = MonoSample(src[ipos].0 * (1.0 - f) + src[ip1].0 * f);
+            j += 1;
+        }
+    }
+}
+
+/// Coefficients of a biquad (second-order) IIR filter in Direct Form I.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BiquadCoeffs {
+    pub b0: f32,
+    pub b1: f32,
+    pub b2: f32,
+    pub a1: f32,
+    pub a2: f32,
+}
+
+/// The four delayed samples a Direct Form I biquad carries between buffers:
+/// the two previous inputs and the two previous outputs.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct BiquadState {
+    x1: f32,
+    x2: f32,
+    y1: f32,
+    y2: f32,
+}
+
+/// Process one channel through a biquad filter, carrying the delay state across
+/// buffer boundaries.
+///
+/// This Direct Form I loop does *not* auto-vectorize: each output
+/// `y[n] = b0*x[n] + b1*x[n-1] + b2*x[n-2] - a1*y[n-1] - a2*y[n-2]` feeds back
+/// into the next iteration through `y[n-1]`/`y[n-2]`, so the iterations form a
+/// serial dependency chain the compiler cannot turn into a SIMD loop.
+pub fn biquad_process(
+    dst: &mut [MonoSample],
+    src: &[MonoSample],
+    coeffs: &BiquadCoeffs,
+    state: &mut BiquadState,
+) {
+    let dst_known_bounds = &mut dst[0..src.len()];
+    for i in 0..src.len() {
+        let x = src[i].0;
+        let y = coeffs.b0 * x + coeffs.b1 * state.x1 + coeffs.b2 * state.x2
+            - coeffs.a1 * state.y1
+            - coeffs.a2 * state.y2;
+        state.x2 = state.x1;
+        state.x1 = x;
+        state.y2 = state.y1;
+        state.y1 = y;
+        dst_known_bounds[i] = MonoSample(y);
+    }
+}
+
+/// Number of independent channels processed per call by the multi-channel
+/// biquad — one filter per SIMD lane.
+pub const BIQUAD_LANES: usize = 4;
+
+/// Delay state for [`biquad_process_channels`], one set of history samples per
+/// lane.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct BiquadStateN {
+    x1: [f32; BIQUAD_LANES],
+    x2: [f32; BIQUAD_LANES],
+    y1: [f32; BIQUAD_LANES],
+    y2: [f32; BIQUAD_LANES],
+}
+
+/// Process `BIQUAD_LANES` independent channels through the same biquad.
+///
+/// The serial feedback dependency that blocks [`biquad_process`] is *along
+/// time*; the channels are independent of each other. By laying the channels
+/// out as the contiguous inner array and running the difference equation across
+/// the lanes, the per-sample work becomes a straight element-wise vector
+/// operation the compiler can vectorize — "vectorize across channels, not
+/// across time".
+pub fn biquad_process_channels(
+    dst: &mut [[f32; BIQUAD_LANES]],
+    src: &[[f32; BIQUAD_LANES]],
+    coeffs: &BiquadCoeffs,
+    state: &mut BiquadStateN,
+) {
+    let dst_known_bounds = &mut dst[0..src.len()];
+    for i in 0..src.len() {
+        let mut out = [0.0f32; BIQUAD_LANES];
+        for c in 0..BIQUAD_LANES {
+            let x = src[i][c];
+            let y = coeffs.b0 * x + coeffs.b1 * state.x1[c] + coeffs.b2 * state.x2[c]
+                - coeffs.a1 * state.y1[c]
+                - coeffs.a2 * state.y2[c];
+            state.x2[c] = state.x1[c];
+            state.x1[c] = x;
+            state.y2[c] = state.y1[c];
+            state.y1[c] = y;
+            out[c] = y;
+        }
+        dst_known_bounds[i] = out;
+    }
+}
+
+/// Broadcast a mono source into `N` interleaved output channels, applying a
+/// per-channel gain.
+///
+/// This is the generalization of `mix_mono_to_stereo_3` to arbitrary channel
+/// counts — stereo (`N = 2`), quad (`N = 4`), 5.1 (`N = 6`), and so on. Keeping
+/// the output frame as a contiguous `[f32; N]` (the same `#[repr]`-contiguous
+/// layout trick that let the stereo mixer auto-vectorize) lets the compiler
+/// emit vector stores for the channel loop when the frame width lines up with a
+/// SIMD register. The `mix_interleaved_*` tests pin the numerical result for
+/// `N = 2`, `4` and `6`; they do not check which `N` are vectorized.
+pub fn mix_interleaved<const N: usize>(
+    dst: &mut [[f32; N]],
+    src: &[MonoSample],
+    gains: &[f32; N],
+) {
+    let dst_known_bounds = &mut dst[0..src.len()];
+    for i in 0..src.len() {
+        let x = src[i].0;
+        let mut frame = [0.0f32; N];
+        for c in 0..N {
+            frame[c] = x * gains[c];
+        }
+        dst_known_bounds[i] = frame;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(target_arch = "x86_64")]
     #[test]
     fn intrinsics() {
         let src = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
@@ -129,6 +538,7 @@ mod tests {
         );
     }
 
+    #[cfg(target_arch = "x86_64")]
     #[test]
     fn intrinsics_rust() {
         let src = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
@@ -164,4 +574,151 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn dispatch() {
+        let src = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let mut dst = vec![0.0; src.len() * 2];
+        mix_mono_to_stereo_dispatch(&mut dst, &src, 0.25, 2.0);
+        assert_eq!(
+            dst,
+            vec![
+                0.25, 2.0, 0.5, 4.0, 0.75, 6.0, 1.0, 8.0, 1.25, 10.0, 1.5, 12.0, 1.75, 14.0, 2.0,
+                16.0
+            ]
+        );
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    #[test]
+    fn portable() {
+        let src = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let mut dst = vec![0.0; src.len() * 2];
+        mix_mono_to_stereo_portable(&mut dst, &src, 0.25, 2.0);
+        assert_eq!(
+            dst,
+            vec![
+                0.25, 2.0, 0.5, 4.0, 0.75, 6.0, 1.0, 8.0, 1.25, 10.0, 1.5, 12.0, 1.75, 14.0, 2.0,
+                16.0
+            ]
+        );
+    }
+
+    #[test]
+    fn dispatch_tail() {
+        // a length that is a multiple of four but not of eight exercises the
+        // AVX scalar tail; the result must match regardless of the CPU the
+        // dispatcher selects for
+        let src = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0];
+        let mut dst = vec![0.0; src.len() * 2];
+        mix_mono_to_stereo_dispatch(&mut dst, &src, 0.5, 3.0);
+        let mut expected = vec![0.0; src.len() * 2];
+        for (i, x) in src.iter().enumerate() {
+            expected[i * 2] = x * 0.5;
+            expected[i * 2 + 1] = x * 3.0;
+        }
+        assert_eq!(dst, expected);
+    }
+
+    #[test]
+    fn resample_upsample_2x() {
+        // doubling the sample rate puts a midpoint between each source pair and
+        // clamps the trailing tap to the last source sample
+        let src = vec![0.0, 2.0, 4.0, 6.0]
+            .iter()
+            .map(|x| MonoSample(*x))
+            .collect::<Vec<_>>();
+        let out_len = src.len() * 2;
+        let mut dst = vec![MonoSample(0.0); out_len];
+        resample_linear(&mut dst, &src, 1, 2);
+        let expected = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 6.0]
+            .iter()
+            .map(|x| MonoSample(*x))
+            .collect::<Vec<_>>();
+        assert_eq!(dst, expected);
+    }
+
+    #[test]
+    fn biquad_impulse_matches_channels() {
+        let coeffs = BiquadCoeffs {
+            b0: 0.5,
+            b1: 0.3,
+            b2: 0.1,
+            a1: -0.2,
+            a2: 0.05,
+        };
+        // impulse response: a single unit sample followed by zeros
+        let mut impulse = vec![MonoSample(0.0); 16];
+        impulse[0] = MonoSample(1.0);
+
+        let mut single = vec![MonoSample(0.0); impulse.len()];
+        let mut state = BiquadState::default();
+        biquad_process(&mut single, &impulse, &coeffs, &mut state);
+
+        // feed the same impulse into every lane of the multi-channel filter
+        let src: Vec<[f32; BIQUAD_LANES]> =
+            impulse.iter().map(|s| [s.0; BIQUAD_LANES]).collect();
+        let mut multi = vec![[0.0f32; BIQUAD_LANES]; src.len()];
+        let mut state_n = BiquadStateN::default();
+        biquad_process_channels(&mut multi, &src, &coeffs, &mut state_n);
+
+        for (scalar, lanes) in single.iter().zip(multi.iter()) {
+            for lane in lanes {
+                assert_eq!(scalar.0, *lane);
+            }
+        }
+    }
+
+    #[test]
+    fn mix_interleaved_stereo() {
+        // N = 2: the stereo layout that mix_mono_to_stereo_3 targets. Whether
+        // the compiler vectorizes this generic version is left to inspection of
+        // the emitted code; this test only pins the numerical result.
+        let src = vec![1.0, 2.0, 3.0, 4.0]
+            .iter()
+            .map(|x| MonoSample(*x))
+            .collect::<Vec<_>>();
+        let mut dst = vec![[0.0f32; 2]; src.len()];
+        mix_interleaved(&mut dst, &src, &[0.25, 2.0]);
+        assert_eq!(dst, vec![[0.25, 2.0], [0.5, 4.0], [0.75, 6.0], [1.0, 8.0]]);
+    }
+
+    #[test]
+    fn mix_interleaved_quad() {
+        // N = 4: a frame width that matches a 128-bit SIMD register. Whether the
+        // broadcast-and-store is actually vectorized is left to inspection of
+        // the emitted code.
+        let src = vec![1.0, 2.0].iter().map(|x| MonoSample(*x)).collect::<Vec<_>>();
+        let mut dst = vec![[0.0f32; 4]; src.len()];
+        let gains = [1.0, 0.5, 0.25, 0.125];
+        mix_interleaved(&mut dst, &src, &gains);
+        assert_eq!(dst, vec![[1.0, 0.5, 0.25, 0.125], [2.0, 1.0, 0.5, 0.25]]);
+    }
+
+    #[test]
+    fn mix_interleaved_surround() {
+        // N = 6 (5.1): a frame width that is not a power of two. Whatever the
+        // compiler does with the trailing channels, the result must stay
+        // correct, which is all this test checks.
+        let src = vec![2.0].iter().map(|x| MonoSample(*x)).collect::<Vec<_>>();
+        let mut dst = vec![[0.0f32; 6]; src.len()];
+        let gains = [1.0, 0.5, 0.25, 0.125, 0.0625, 0.03125];
+        mix_interleaved(&mut dst, &src, &gains);
+        assert_eq!(dst, vec![[2.0, 1.0, 0.5, 0.25, 0.125, 0.0625]]);
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn resample_variants_agree() {
+        let src = (0..16).map(|x| MonoSample(x as f32)).collect::<Vec<_>>();
+        let out_len = src.len() * 3 / 2;
+        let mut scalar = vec![MonoSample(0.0); out_len];
+        let mut auto = vec![MonoSample(0.0); out_len];
+        let mut intr = vec![MonoSample(0.0); out_len];
+        resample_linear(&mut scalar, &src, 2, 3);
+        resample_linear_auto(&mut auto, &src, 2, 3);
+        resample_linear_intrinsics_rust(&mut intr, &src, 2, 3);
+        assert_eq!(scalar, auto);
+        assert_eq!(scalar, intr);
+    }
 }